@@ -1,19 +1,27 @@
 //! Library for FTX Derivatives (previously LedgerX) API access
+#![allow(clippy::result_large_err)]
 
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Mutex, RwLock},
+};
 
-use futures::future::try_join_all;
+use futures::future::{try_join_all, BoxFuture};
 use rust_decimal::Decimal;
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Serialize};
 use thiserror::Error;
 
 pub mod structs;
+mod websocket;
 
 use structs::{
-    contract::{Contract, ContractTicker, ContractTickerLastTrade, ContractTickerResult},
+    contract::{ContractTicker, ContractTickerResult},
+    order::{NewOrder, OrderStatus, OrderStatusResult},
     positions::Position,
     trade::Trade,
-    transaction::Transaction,
+    transaction::{RawTransaction, Transaction},
+    AssetMeta,
 };
 
 #[derive(Error, Debug)]
@@ -35,32 +43,148 @@ pub enum FTXDerivativesError {
     },
     #[error("unknown currency")]
     UnknownCurrency { currency: String },
+    #[error("api returned a non-2xx response: {status}")]
+    ApiError { status: u16, body: String },
+    #[error("error caught in websocket connection")]
+    WebsocketError {
+        #[from]
+        source: tokio_tungstenite::tungstenite::Error,
+    },
 }
 
+type TokenRefreshFn = Box<dyn Fn() -> BoxFuture<'static, String> + Send + Sync>;
+
 pub struct FTXDerivatives {
     reqwest_client: reqwest::Client,
-    api_key: String,
+    api_key: RwLock<String>,
+    token_refresh: Option<TokenRefreshFn>,
+    currency_precision: Mutex<HashMap<String, u32>>,
 }
 
 impl FTXDerivatives {
     pub fn new(api_key: &str) -> Self {
+        Self::with_client(api_key, reqwest::Client::new())
+    }
+
+    /// Builds a client around a caller-provided `reqwest::Client`, e.g. one
+    /// configured with custom timeouts or a proxy.
+    pub fn with_client(api_key: &str, client: reqwest::Client) -> Self {
         FTXDerivatives {
-            reqwest_client: reqwest::Client::new(),
-            api_key: api_key.to_owned(),
+            reqwest_client: client,
+            api_key: RwLock::new(api_key.to_owned()),
+            token_refresh: None,
+            currency_precision: Mutex::new(default_currency_precision()),
         }
     }
 
-    async fn get_list<T: DeserializeOwned>(
+    /// Builds a client whose currency precision table is seeded with the
+    /// defaults, overridden by the entries in `precision`. Assets not
+    /// present in either are looked up lazily from the assets metadata
+    /// endpoint the first time they're needed.
+    pub fn with_currency_precision(api_key: &str, precision: HashMap<String, u32>) -> Self {
+        let client = Self::with_client(api_key, reqwest::Client::new());
+        client.currency_precision.lock().unwrap().extend(precision);
+        client
+    }
+
+    /// Registers a callback invoked to obtain a fresh JWT whenever a request
+    /// comes back `401 Unauthorized`. The failed request is retried once
+    /// with the refreshed token.
+    pub fn with_token_refresh<F, Fut>(mut self, refresh: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = String> + Send + 'static,
+    {
+        self.token_refresh = Some(Box::new(move || Box::pin(refresh())));
+        self
+    }
+
+    fn current_token(&self) -> String {
+        self.api_key.read().unwrap().clone()
+    }
+
+    async fn refresh_token(&self) -> Option<String> {
+        let new_token = (self.token_refresh.as_ref()?)().await;
+        *self.api_key.write().unwrap() = new_token.clone();
+        Some(new_token)
+    }
+
+    // Sends a request with the current JWT attached, transparently
+    // refreshing and retrying once if the server comes back 401.
+    async fn execute<B: Serialize>(
         &self,
+        method: reqwest::Method,
         url: &str,
-    ) -> Result<structs::ListResult<T>, FTXDerivativesError> {
-        // TODO: implement paging
+        query: &[(&str, u32)],
+        body: Option<&B>,
+    ) -> Result<reqwest::Response, FTXDerivativesError> {
+        let build = |token: &str| {
+            let mut req = self
+                .reqwest_client
+                .request(method.clone(), url)
+                .query(query)
+                .header("Authorization", format!("JWT {}", token));
+            if let Some(b) = body {
+                req = req.json(b);
+            }
+            req
+        };
+
+        let res = build(&self.current_token()).send().await?;
+
+        if res.status() == reqwest::StatusCode::UNAUTHORIZED {
+            if let Some(new_token) = self.refresh_token().await {
+                return Ok(build(&new_token).send().await?);
+            }
+        }
+
+        Ok(res)
+    }
+
+    /// Overrides (or adds) the decimal precision used to rescale amounts in
+    /// the given asset, e.g. when a new collateral asset is listed before
+    /// this crate knows about it.
+    pub fn set_currency_precision(&self, asset: &str, decimals: u32) {
+        self.currency_precision
+            .lock()
+            .unwrap()
+            .insert(asset.to_owned(), decimals);
+    }
+
+    async fn get_num_decimals(&self, currency: &str) -> Result<u32, FTXDerivativesError> {
+        if let Some(decimals) = self.currency_precision.lock().unwrap().get(currency) {
+            return Ok(*decimals);
+        }
+
+        self.fetch_currency_precision(currency).await
+    }
+
+    async fn fetch_currency_precision(&self, currency: &str) -> Result<u32, FTXDerivativesError> {
+        const URL: &str = "https://api.ledgerx.com/trading/contracts/meta/assets";
+        let assets: Vec<AssetMeta> = self.get_list(URL).await?;
+
+        let mut table = self.currency_precision.lock().unwrap();
+        for asset in assets {
+            table.entry(asset.asset).or_insert(asset.decimals);
+        }
+
+        table
+            .get(currency)
+            .copied()
+            .ok_or_else(|| FTXDerivativesError::UnknownCurrency {
+                currency: currency.to_owned(),
+            })
+    }
+
+    // Fetches a single page from an already-built request URL: the absolute
+    // `next` URLs returned by the API, which already embed their own query
+    // string. The first page goes through `get_list_page` instead.
+    async fn get_page<T: DeserializeOwned>(
+        &self,
+        url: &str,
+    ) -> Result<structs::ListResult<Vec<T>>, FTXDerivativesError> {
         let res = self
-            .reqwest_client
-            .get(url)
-            .query(&[("limit", 100)])
-            .header("Authorization", format!("JWT {}", &self.api_key))
-            .send()
+            .execute(reqwest::Method::GET, url, &[], None::<&()>)
             .await?
             .text()
             .await?;
@@ -71,25 +195,62 @@ impl FTXDerivatives {
         Ok(json?)
     }
 
+    pub async fn get_list_page<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<structs::ListResult<Vec<T>>, FTXDerivativesError> {
+        let res = self
+            .execute(
+                reqwest::Method::GET,
+                url,
+                &[("limit", limit), ("offset", offset)],
+                None::<&()>,
+            )
+            .await?
+            .text()
+            .await?;
+        let json = serde_json::from_str(&res);
+        if json.is_err() {
+            println!("{}", res)
+        }
+        Ok(json?)
+    }
+
+    async fn get_list<T: DeserializeOwned>(&self, url: &str) -> Result<Vec<T>, FTXDerivativesError> {
+        const PAGE_SIZE: u32 = 100;
+
+        let mut page: structs::ListResult<Vec<T>> = self.get_list_page(url, PAGE_SIZE, 0).await?;
+        let total_count = page.meta.total_count as usize;
+        let mut data = page.data;
+
+        while let Some(next) = page.meta.next.take() {
+            if data.len() >= total_count {
+                break;
+            }
+            page = self.get_page(&next).await?;
+            data.extend(page.data);
+        }
+
+        Ok(data)
+    }
+
     pub async fn get_positions(&self) -> Result<Vec<Position>, FTXDerivativesError> {
         const URL: &str = "https://api.ledgerx.com/trading/positions";
-        let res: Vec<Position> = self.get_list(URL).await?.data;
-
-        res.into_iter()
-            .map(|p| {
-                Ok(Position {
-                    contract: convert_contract(p.contract)?,
-                    ..p
-                })
-            })
-            .collect()
+        self.get_list(URL).await
     }
 
     pub async fn get_transactions(&self) -> Result<Vec<Transaction>, FTXDerivativesError> {
         const URL: &str = "https://api.ledgerx.com/funds/transactions";
-        let res: Vec<Transaction> = self.get_list(URL).await?.data;
+        let res: Vec<RawTransaction> = self.get_list(URL).await?;
 
-        res.into_iter().map(convert_transaction).collect()
+        let mut transactions = Vec::with_capacity(res.len());
+        for raw in res {
+            let num_decimals = self.get_num_decimals(&raw.asset).await?;
+            transactions.push(Transaction::try_from((raw, num_decimals))?);
+        }
+        Ok(transactions)
     }
 
     pub async fn get_contract_ticker(
@@ -100,15 +261,14 @@ impl FTXDerivatives {
             "https://api.ledgerx.com/trading/contracts/{}/ticker",
             contract_id
         );
-        let res = self
+        Ok(self
             .reqwest_client
             .get(url)
             .send()
             .await?
             .json::<ContractTickerResult>()
             .await?
-            .data;
-        convert_contract_ticker(res)
+            .data)
     }
 
     pub async fn get_contracts_ticker(
@@ -125,9 +285,16 @@ impl FTXDerivatives {
 
     pub async fn get_trades(&self) -> Result<Vec<Trade>, FTXDerivativesError> {
         const URL: &str = "https://api.ledgerx.com/trading/trades";
-        let res: Vec<Trade> = self.get_list(URL).await?.data;
+        self.get_list(URL).await
+    }
 
-        res.into_iter().map(convert_trade).collect()
+    pub async fn get_trades_paged(
+        &self,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<Trade>, FTXDerivativesError> {
+        const URL: &str = "https://api.ledgerx.com/trading/trades";
+        Ok(self.get_list_page(URL, limit, offset).await?.data)
     }
 
     pub async fn get_balances(&self) -> Result<HashMap<String, Decimal>, FTXDerivativesError> {
@@ -144,114 +311,97 @@ impl FTXDerivatives {
 
         Ok(balances)
     }
-}
 
-fn get_num_decimals(currency: &str) -> Result<u32, FTXDerivativesError> {
-    Ok(match currency {
-        "USD" => 2,
-        "CBTC" => 8,
-        "ETH" => 9,
-        _ => {
-            return Err(FTXDerivativesError::UnknownCurrency {
-                currency: currency.to_owned(),
-            })
+    async fn send_json<T: DeserializeOwned, B: Serialize>(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<&B>,
+    ) -> Result<T, FTXDerivativesError> {
+        let res = self.execute(method, url, &[], body).await?;
+        let status = res.status();
+        let text = res.text().await?;
+
+        if !status.is_success() {
+            return Err(FTXDerivativesError::ApiError {
+                status: status.as_u16(),
+                body: text,
+            });
         }
-    })
-}
 
-fn rescale_number(amount: Decimal, num_decimals: u32) -> Result<Decimal, FTXDerivativesError> {
-    let mut res = amount;
-    res.set_scale(num_decimals)?;
-    Ok(res)
-}
+        Ok(serde_json::from_str(&text)?)
+    }
 
-fn convert_contract(contract: Contract) -> Result<Contract, FTXDerivativesError> {
-    match contract {
-        Contract::Option {
-            id,
-            name,
-            is_call,
-            strike_price,
-            min_increment,
-            date_live,
-            date_expires,
-            date_exercise,
-            open_interest,
-            multiplier,
-            label,
-            active,
-            underlying_asset,
-            collateral_asset,
-            option_type,
-        } => Ok(Contract::Option {
-            id,
-            name,
-            is_call,
-            strike_price: rescale_number(strike_price, 2)?,
-            min_increment,
-            date_live,
-            date_expires,
-            date_exercise,
-            open_interest,
-            multiplier,
-            label,
-            active,
-            underlying_asset,
-            collateral_asset,
-            option_type,
-        }),
-        x => Ok(x),
+    pub async fn place_order(&self, order: &NewOrder) -> Result<OrderStatus, FTXDerivativesError> {
+        const URL: &str = "https://api.ledgerx.com/trading/orders";
+        self.send_json::<OrderStatusResult, NewOrder>(reqwest::Method::POST, URL, Some(order))
+            .await
+            .map(|r| r.data)
     }
-}
 
-fn convert_contract_ticker(ticker: ContractTicker) -> Result<ContractTicker, FTXDerivativesError> {
-    let last_trade = match ticker.last_trade {
-        Some(t) => Some(ContractTickerLastTrade {
-            price: rescale_number(t.price, 2)?,
-            ..t
-        }),
-        None => None,
-    };
-    Ok(ContractTicker {
-        ask: rescale_number(ticker.ask, 2)?,
-        bid: rescale_number(ticker.bid, 2)?,
-        last_trade,
-        ..ticker
-    })
-}
+    pub async fn cancel_order(&self, order_id: &str) -> Result<OrderStatus, FTXDerivativesError> {
+        let url = format!("https://api.ledgerx.com/trading/orders/{}", order_id);
+        self.send_json::<OrderStatusResult, ()>(reqwest::Method::DELETE, &url, None)
+            .await
+            .map(|r| r.data)
+    }
+
+    pub async fn cancel_all(&self) -> Result<(), FTXDerivativesError> {
+        const URL: &str = "https://api.ledgerx.com/trading/orders";
+        self.cancel_all_at(URL).await
+    }
+
+    async fn cancel_all_at(&self, url: &str) -> Result<(), FTXDerivativesError> {
+        let res = self
+            .execute(reqwest::Method::DELETE, url, &[], None::<&()>)
+            .await?;
+        let status = res.status();
+        let text = res.text().await?;
+
+        if !status.is_success() {
+            return Err(FTXDerivativesError::ApiError {
+                status: status.as_u16(),
+                body: text,
+            });
+        }
 
-fn convert_transaction(transaction: Transaction) -> Result<Transaction, FTXDerivativesError> {
-    fn rescale_opt(
-        orig: Option<Decimal>,
-        num_decimals: u32,
-    ) -> Result<Option<Decimal>, FTXDerivativesError> {
-        match orig {
-            Some(o) => Ok(Some(rescale_number(o, num_decimals)?)),
-            None => Ok(None),
+        // Bulk cancel-all commonly comes back `204 No Content` with an empty
+        // body, which isn't valid JSON; only parse when there's something to
+        // parse, since we just need to know the call succeeded.
+        if !text.trim().is_empty() {
+            serde_json::from_str::<serde_json::Value>(&text)?;
         }
+
+        Ok(())
     }
 
-    let num_decimals = get_num_decimals(&transaction.asset)?;
+    pub async fn replace_order(
+        &self,
+        order_id: &str,
+        order: &NewOrder,
+    ) -> Result<OrderStatus, FTXDerivativesError> {
+        let url = format!("https://api.ledgerx.com/trading/orders/{}", order_id);
+        self.send_json::<OrderStatusResult, NewOrder>(reqwest::Method::PUT, &url, Some(order))
+            .await
+            .map(|r| r.data)
+    }
 
-    Ok(Transaction {
-        amount: rescale_number(transaction.amount, num_decimals)?,
-        debit_pre_balance: rescale_opt(transaction.debit_pre_balance, num_decimals)?,
-        debit_post_balance: rescale_opt(transaction.debit_post_balance, num_decimals)?,
-        credit_pre_balance: rescale_opt(transaction.credit_pre_balance, num_decimals)?,
-        credit_post_balance: rescale_opt(transaction.credit_post_balance, num_decimals)?,
-        net_change: rescale_number(transaction.net_change, num_decimals)?,
-        ..transaction
-    })
 }
 
-fn convert_trade(trade: Trade) -> Result<Trade, FTXDerivativesError> {
-    Ok(Trade {
-        filled_price: rescale_number(trade.filled_price, 2)?,
-        fee: rescale_number(trade.fee, 2)?,
-        rebate: rescale_number(trade.rebate, 2)?,
-        premium: rescale_number(trade.premium, 2)?,
-        ..trade
-    })
+fn default_currency_precision() -> HashMap<String, u32> {
+    [("USD", 2), ("CBTC", 8), ("ETH", 9)]
+        .into_iter()
+        .map(|(asset, decimals)| (asset.to_owned(), decimals))
+        .collect()
+}
+
+pub(crate) fn rescale_number(
+    amount: Decimal,
+    num_decimals: u32,
+) -> Result<Decimal, FTXDerivativesError> {
+    let mut res = amount;
+    res.set_scale(num_decimals)?;
+    Ok(res)
 }
 
 #[cfg(test)]
@@ -259,6 +409,10 @@ mod tests {
     use std::env;
 
     use dotenv::dotenv;
+    use wiremock::{
+        matchers::{body_json, header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
 
     use super::*;
 
@@ -312,4 +466,310 @@ mod tests {
         let balances = client.get_balances().await.unwrap();
         println!("{:#?}", balances);
     }
+
+    fn list_page(data: serde_json::Value, total_count: u32, next: Option<String>) -> serde_json::Value {
+        serde_json::json!({
+            "meta": {
+                "total_count": total_count,
+                "next": next,
+                "previous": null,
+                "limit": 2,
+                "offset": 0,
+            },
+            "data": data,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_list_follows_next_across_pages() {
+        let server = MockServer::start().await;
+        let next = format!("{}/list?offset=2", server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/list"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(list_page(
+                serde_json::json!([{"asset": "USD", "decimals": 2}, {"asset": "ETH", "decimals": 9}]),
+                3,
+                Some(next.clone()),
+            )))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/list"))
+            .and(query_param("offset", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(list_page(
+                serde_json::json!([{"asset": "CBTC", "decimals": 8}]),
+                3,
+                None,
+            )))
+            .mount(&server)
+            .await;
+
+        let client = FTXDerivatives::new("test-token");
+        let assets: Vec<AssetMeta> = client
+            .get_list(&format!("{}/list", server.uri()))
+            .await
+            .unwrap();
+
+        assert_eq!(assets.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_list_stops_once_total_count_is_reached() {
+        // The server never nulls `next`; the length guard must stop the loop
+        // on its own. No mock is registered for the `next` URL, so fetching
+        // it would fail the test.
+        let server = MockServer::start().await;
+        let next = format!("{}/list?offset=2", server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/list"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(list_page(
+                serde_json::json!([{"asset": "USD", "decimals": 2}, {"asset": "ETH", "decimals": 9}]),
+                2,
+                Some(next),
+            )))
+            .mount(&server)
+            .await;
+
+        let client = FTXDerivatives::new("test-token");
+        let assets: Vec<AssetMeta> = client
+            .get_list(&format!("{}/list", server.uri()))
+            .await
+            .unwrap();
+
+        assert_eq!(assets.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_execute_retries_once_with_refreshed_token_on_401() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/thing"))
+            .and(header("Authorization", "JWT stale-token"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/thing"))
+            .and(header("Authorization", "JWT fresh-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"ok": true})))
+            .mount(&server)
+            .await;
+
+        let client = FTXDerivatives::new("stale-token")
+            .with_token_refresh(|| Box::pin(async { "fresh-token".to_owned() }));
+
+        let res = client
+            .execute(
+                reqwest::Method::GET,
+                &format!("{}/thing", server.uri()),
+                &[],
+                None::<&()>,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), reqwest::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_execute_propagates_401_when_no_refresh_is_configured() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/thing"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = FTXDerivatives::new("stale-token");
+
+        let res = client
+            .execute(
+                reqwest::Method::GET,
+                &format!("{}/thing", server.uri()),
+                &[],
+                None::<&()>,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), reqwest::StatusCode::UNAUTHORIZED);
+    }
+
+    // place_order/cancel_order/replace_order are thin wrappers that build a
+    // fixed LedgerX URL and hand off to `send_json`; exercising `send_json`
+    // directly against a mock server covers their behavior without needing
+    // to redirect the hard-coded production URLs.
+    #[tokio::test]
+    async fn test_place_order_serializes_price_as_scaled_integer() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/orders"))
+            .and(body_json(serde_json::json!({
+                "contract_id": 1,
+                "side": "bid",
+                "size": 5,
+                "price": 123456,
+                "order_type": "limit",
+                "time_in_force": "good_til_cancelled",
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "id": "order-1",
+                    "contract_id": 1,
+                    "side": "bid",
+                    "size": 5,
+                    "filled_size": 0,
+                    "price": 123456,
+                    "order_type": "limit",
+                    "status_type": "open",
+                    "created": "2024-01-01T00:00:00Z",
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let order = NewOrder::new(1, structs::trade::TradeSide::Bid, 5, Decimal::new(123456, 2));
+        let client = FTXDerivatives::new("test-token");
+        let status = client
+            .send_json::<OrderStatusResult, NewOrder>(
+                reqwest::Method::POST,
+                &format!("{}/orders", server.uri()),
+                Some(&order),
+            )
+            .await
+            .unwrap()
+            .data;
+
+        assert_eq!(status.price, Decimal::new(123456, 2));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_order_returns_the_cancelled_order() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/orders/order-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "id": "order-1",
+                    "contract_id": 1,
+                    "side": "bid",
+                    "size": 5,
+                    "filled_size": 0,
+                    "price": 123456,
+                    "order_type": "limit",
+                    "status_type": "cancelled",
+                    "created": "2024-01-01T00:00:00Z",
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = FTXDerivatives::new("test-token");
+        let status = client
+            .send_json::<OrderStatusResult, ()>(
+                reqwest::Method::DELETE,
+                &format!("{}/orders/order-1", server.uri()),
+                None,
+            )
+            .await
+            .unwrap()
+            .data;
+
+        assert_eq!(status.status_type, "cancelled");
+    }
+
+    #[tokio::test]
+    async fn test_replace_order_serializes_price_as_scaled_integer() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/orders/order-1"))
+            .and(body_json(serde_json::json!({
+                "contract_id": 1,
+                "side": "ask",
+                "size": 3,
+                "price": 99900,
+                "order_type": "limit",
+                "time_in_force": "good_til_cancelled",
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "id": "order-1",
+                    "contract_id": 1,
+                    "side": "ask",
+                    "size": 3,
+                    "filled_size": 0,
+                    "price": 99900,
+                    "order_type": "limit",
+                    "status_type": "open",
+                    "created": "2024-01-01T00:00:00Z",
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let order = NewOrder::new(1, structs::trade::TradeSide::Ask, 3, Decimal::new(99900, 2));
+        let client = FTXDerivatives::new("test-token");
+        let status = client
+            .send_json::<OrderStatusResult, NewOrder>(
+                reqwest::Method::PUT,
+                &format!("{}/orders/order-1", server.uri()),
+                Some(&order),
+            )
+            .await
+            .unwrap()
+            .data;
+
+        assert_eq!(status.price, Decimal::new(99900, 2));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_treats_empty_body_as_success() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/orders"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+
+        let client = FTXDerivatives::new("test-token");
+        client
+            .cancel_all_at(&format!("{}/orders", server.uri()))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_still_surfaces_api_errors() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/orders"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+            .mount(&server)
+            .await;
+
+        let client = FTXDerivatives::new("test-token");
+        let err = client
+            .cancel_all_at(&format!("{}/orders", server.uri()))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            FTXDerivativesError::ApiError { status: 500, .. }
+        ));
+    }
 }
@@ -34,6 +34,7 @@ pub enum Contract {
         id: u64,
         name: Option<String>,
         is_call: bool,
+        #[serde(deserialize_with = "super::money::deserialize")]
         strike_price: Decimal,
         min_increment: u32,
         date_live: DateTime,
@@ -57,7 +58,9 @@ pub struct ContractTickerResult {
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct ContractTicker {
+    #[serde(deserialize_with = "super::money::deserialize")]
     pub ask: Decimal,
+    #[serde(deserialize_with = "super::money::deserialize")]
     pub bid: Decimal,
     pub volume_24h: u32,
     pub last_trade: Option<ContractTickerLastTrade>,
@@ -67,6 +70,7 @@ pub struct ContractTicker {
 #[derive(Deserialize, Debug, Clone)]
 pub struct ContractTickerLastTrade {
     pub id: u64,
+    #[serde(deserialize_with = "super::money::deserialize")]
     pub price: Decimal,
     pub size: u32,
     pub time: DateTime,
@@ -4,9 +4,12 @@ use serde::Deserialize;
 pub type DateTime = chrono::DateTime<chrono::Utc>;
 
 pub mod contract;
+pub(crate) mod money;
+pub mod order;
 pub mod positions;
 pub mod transaction;
 pub mod trade;
+pub mod ws;
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct ListMetaResult {
@@ -22,3 +25,9 @@ pub struct ListResult<T> {
     pub meta: ListMetaResult,
     pub data: T,
 }
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AssetMeta {
+    pub asset: String,
+    pub decimals: u32,
+}
@@ -0,0 +1,46 @@
+//! Serializer/deserializer pair for API fields that carry a money amount
+//! pre-multiplied by the exchange's fixed 2-decimal-place scale, so the
+//! rescale happens once, at the point the field is parsed or built, instead
+//! of in a later conversion pass.
+//!
+//! Every `Decimal` field backed by the wire format below needs
+//! `#[serde(deserialize_with = "super::money::deserialize")]` (and
+//! `serialize_with` on outbound fields like `NewOrder.price`) — there's no
+//! compiler check that catches a plain `Decimal` field slipping through
+//! unscaled, so grep for `Decimal` across `structs/` before closing out a
+//! request that touches money handling.
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const DECIMALS: u32 = 2;
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = <Decimal as Deserialize>::deserialize(deserializer)?;
+    crate::rescale_number(value, DECIMALS).map_err(serde::de::Error::custom)
+}
+
+pub fn serialize<S>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    // This is order-entry money, so silently rounding away sub-cent
+    // precision (e.g. 123.456 -> 12346) would be surprising; reject it
+    // instead of guessing what the caller meant. Normalize first so a
+    // harmless trailing zero (123.400, scale 3) isn't rejected.
+    if value.normalize().scale() > DECIMALS {
+        return Err(serde::ser::Error::custom(format!(
+            "{} has more than {} decimal places",
+            value, DECIMALS
+        )));
+    }
+
+    let scaled = value * Decimal::from(10u64.pow(DECIMALS));
+    let scaled = scaled
+        .to_i64()
+        .ok_or_else(|| serde::ser::Error::custom("amount too large to serialize"))?;
+    scaled.serialize(serializer)
+}
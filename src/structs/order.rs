@@ -0,0 +1,68 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    trade::{OrderType, TradeSide},
+    DateTime,
+};
+
+#[derive(Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeInForce {
+    GoodTilCancelled,
+    ImmediateOrCancel,
+    FillOrKill,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct NewOrder {
+    contract_id: u64,
+    side: TradeSide,
+    size: u32,
+    #[serde(serialize_with = "super::money::serialize")]
+    price: Decimal,
+    order_type: OrderType,
+    time_in_force: TimeInForce,
+}
+
+impl NewOrder {
+    pub fn new(contract_id: u64, side: TradeSide, size: u32, price: Decimal) -> Self {
+        NewOrder {
+            contract_id,
+            side,
+            size,
+            price,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GoodTilCancelled,
+        }
+    }
+
+    pub fn with_order_type(mut self, order_type: OrderType) -> Self {
+        self.order_type = order_type;
+        self
+    }
+
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = time_in_force;
+        self
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct OrderStatusResult {
+    pub data: OrderStatus,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct OrderStatus {
+    pub id: String,
+    pub contract_id: u64,
+    pub side: TradeSide,
+    pub size: u32,
+    pub filled_size: u32,
+    #[serde(deserialize_with = "super::money::deserialize")]
+    pub price: Decimal,
+    pub order_type: OrderType,
+    pub status_type: String,
+    pub created: DateTime,
+}
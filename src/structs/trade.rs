@@ -1,15 +1,18 @@
 use rust_decimal::Decimal;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::DateTime;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum OrderType {
     CustomerLimitOrder,
+    Market,
+    Limit,
+    LimitIfTouched,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum TradeSide {
     Bid,
@@ -21,10 +24,14 @@ pub struct Trade {
     pub id: u64,
     pub contract_id: String,
     pub contract_label: String,
+    #[serde(deserialize_with = "super::money::deserialize")]
     pub filled_price: Decimal,
     pub filled_size: u32,
+    #[serde(deserialize_with = "super::money::deserialize")]
     pub fee: Decimal,
+    #[serde(deserialize_with = "super::money::deserialize")]
     pub rebate: Decimal,
+    #[serde(deserialize_with = "super::money::deserialize")]
     pub premium: Decimal,
     pub created: DateTime,
     pub order_type: OrderType,
@@ -1,10 +1,11 @@
-use serde::Deserialize;
 use rust_decimal::Decimal;
+use serde::Deserialize;
 
 use super::DateTime;
+use crate::{rescale_number, FTXDerivativesError};
 
 #[derive(Deserialize, Debug, Clone)]
-#[serde(rename_all = "snake_case")] 
+#[serde(rename_all = "snake_case")]
 pub enum TransactionType {
     FeeTransaction,
     PositionLockTransaction,
@@ -18,7 +19,7 @@ pub enum TransactionType {
 }
 
 #[derive(Deserialize, Debug, Clone)]
-#[serde(rename_all = "snake_case")] 
+#[serde(rename_all = "snake_case")]
 pub enum TransactionState {
     Pending,
     Cached,
@@ -26,8 +27,12 @@ pub enum TransactionState {
     Failed,
 }
 
+/// A transaction as returned by the API, before its money fields have been
+/// rescaled. The divisor for those fields depends on `asset`, which isn't
+/// known until after this has been parsed, so the rescale happens in a
+/// second pass via `TryFrom<(RawTransaction, u32)>`.
 #[derive(Deserialize, Debug, Clone)]
-pub struct Transaction {
+pub struct RawTransaction {
     pub id: u64,
     pub created: DateTime,
     pub last_updated: DateTime,
@@ -52,3 +57,66 @@ pub struct Transaction {
     pub credit_participant_name: Option<String>,
     pub net_change: Decimal,
 }
+
+#[derive(Debug, Clone)]
+pub struct Transaction {
+    pub id: u64,
+    pub created: DateTime,
+    pub last_updated: DateTime,
+    pub transaction_type: TransactionType,
+    pub amount: Decimal,
+    pub debit_account_field_name: String,
+    pub credit_account_field_name: String,
+    pub settlement_id: Option<u64>,
+    pub state: TransactionState,
+    pub deposit_notice_id: Option<u64>,
+    pub trade_id: Option<u64>,
+    pub group_id: Option<String>,
+    pub asset: String,
+    pub debit_pre_balance: Option<Decimal>,
+    pub debit_post_balance: Option<Decimal>,
+    pub credit_pre_balance: Option<Decimal>,
+    pub credit_post_balance: Option<Decimal>,
+    pub debit_participant_name: Option<String>,
+    pub credit_participant_name: Option<String>,
+    pub net_change: Decimal,
+}
+
+impl TryFrom<(RawTransaction, u32)> for Transaction {
+    type Error = FTXDerivativesError;
+
+    fn try_from((raw, num_decimals): (RawTransaction, u32)) -> Result<Self, Self::Error> {
+        fn rescale_opt(
+            orig: Option<Decimal>,
+            num_decimals: u32,
+        ) -> Result<Option<Decimal>, FTXDerivativesError> {
+            match orig {
+                Some(o) => Ok(Some(rescale_number(o, num_decimals)?)),
+                None => Ok(None),
+            }
+        }
+
+        Ok(Transaction {
+            id: raw.id,
+            created: raw.created,
+            last_updated: raw.last_updated,
+            transaction_type: raw.transaction_type,
+            amount: rescale_number(raw.amount, num_decimals)?,
+            debit_account_field_name: raw.debit_account_field_name,
+            credit_account_field_name: raw.credit_account_field_name,
+            settlement_id: raw.settlement_id,
+            state: raw.state,
+            deposit_notice_id: raw.deposit_notice_id,
+            trade_id: raw.trade_id,
+            group_id: raw.group_id,
+            asset: raw.asset,
+            debit_pre_balance: rescale_opt(raw.debit_pre_balance, num_decimals)?,
+            debit_post_balance: rescale_opt(raw.debit_post_balance, num_decimals)?,
+            credit_pre_balance: rescale_opt(raw.credit_pre_balance, num_decimals)?,
+            credit_post_balance: rescale_opt(raw.credit_post_balance, num_decimals)?,
+            debit_participant_name: raw.debit_participant_name,
+            credit_participant_name: raw.credit_participant_name,
+            net_change: rescale_number(raw.net_change, num_decimals)?,
+        })
+    }
+}
@@ -0,0 +1,125 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use super::{contract::ContractTicker, trade::Trade};
+
+/// A single message parsed off the LedgerX websocket feed.
+///
+/// The server tags every frame with a `type` field; this mirrors that
+/// tagging so a frame decodes straight into the variant it describes,
+/// reusing the same structs as the REST endpoints wherever the shapes
+/// line up.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum FeedMessage {
+    #[serde(rename = "ticker")]
+    Ticker {
+        contract_id: u64,
+        #[serde(flatten)]
+        ticker: ContractTicker,
+    },
+    #[serde(rename = "book_top")]
+    BookTop {
+        contract_id: u64,
+        #[serde(deserialize_with = "super::money::deserialize")]
+        bid: Decimal,
+        bid_size: u32,
+        #[serde(deserialize_with = "super::money::deserialize")]
+        ask: Decimal,
+        ask_size: u32,
+    },
+    #[serde(rename = "action_report")]
+    ExecutedTrade(Trade),
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::FeedMessage;
+
+    #[test]
+    fn deserializes_ticker_with_rescaled_money_fields() {
+        let msg: FeedMessage = serde_json::from_value(serde_json::json!({
+            "type": "ticker",
+            "contract_id": 1,
+            "ask": 10050,
+            "bid": 10000,
+            "volume_24h": 5,
+            "last_trade": null,
+            "time": "2024-01-01T00:00:00Z",
+        }))
+        .unwrap();
+
+        match msg {
+            FeedMessage::Ticker { contract_id, ticker } => {
+                assert_eq!(contract_id, 1);
+                assert_eq!(ticker.ask, Decimal::new(10050, 2));
+                assert_eq!(ticker.bid, Decimal::new(10000, 2));
+            }
+            other => panic!("expected Ticker, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn deserializes_book_top_with_rescaled_money_fields() {
+        let msg: FeedMessage = serde_json::from_value(serde_json::json!({
+            "type": "book_top",
+            "contract_id": 1,
+            "bid": 10000,
+            "bid_size": 5,
+            "ask": 10050,
+            "ask_size": 3,
+        }))
+        .unwrap();
+
+        match msg {
+            FeedMessage::BookTop {
+                contract_id,
+                bid,
+                bid_size,
+                ask,
+                ask_size,
+            } => {
+                assert_eq!(contract_id, 1);
+                assert_eq!(bid, Decimal::new(10000, 2));
+                assert_eq!(bid_size, 5);
+                assert_eq!(ask, Decimal::new(10050, 2));
+                assert_eq!(ask_size, 3);
+            }
+            other => panic!("expected BookTop, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn deserializes_action_report_with_rescaled_money_fields() {
+        let msg: FeedMessage = serde_json::from_value(serde_json::json!({
+            "type": "action_report",
+            "id": 1,
+            "contract_id": "c1",
+            "contract_label": "label",
+            "filled_price": 10000,
+            "filled_size": 1,
+            "fee": 100,
+            "rebate": 0,
+            "premium": 500,
+            "created": "2024-01-01T00:00:00Z",
+            "order_type": "limit",
+            "order_id": "o1",
+            "state": null,
+            "status_type": "filled",
+            "side": "bid",
+            "execution_time": "2024-01-01T00:00:00Z",
+        }))
+        .unwrap();
+
+        match msg {
+            FeedMessage::ExecutedTrade(trade) => {
+                assert_eq!(trade.filled_price, Decimal::new(10000, 2));
+                assert_eq!(trade.fee, Decimal::new(100, 2));
+                assert_eq!(trade.premium, Decimal::new(500, 2));
+            }
+            other => panic!("expected ExecutedTrade, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Streaming market data over the LedgerX websocket feed.
+
+use std::{sync::Arc, time::Duration};
+
+use futures::{SinkExt, Stream, StreamExt};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{client::IntoClientRequest, http::StatusCode, Message},
+};
+
+use crate::{structs::ws::FeedMessage, FTXDerivatives, FTXDerivativesError};
+
+const WS_URL: &str = "wss://api.ledgerx.com/ws";
+const CHANNEL_CAPACITY: usize = 256;
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+impl FTXDerivatives {
+    /// Subscribes to ticker, book-top, and trade updates for the given
+    /// contract IDs, returning a stream of parsed feed messages.
+    ///
+    /// The subscription is held open by a background task that reconnects
+    /// with exponential backoff and re-subscribes to the same contract IDs
+    /// whenever the socket drops. Each reconnect attempt picks up the
+    /// client's current JWT rather than freezing the one in effect when this
+    /// was called, so a token rotated via `with_token_refresh` is honored.
+    ///
+    /// Requires the client to be held in an `Arc` (e.g. `Arc::new(FTXDerivatives::new(key))`)
+    /// so the background task can keep reading the live token for as long as
+    /// the subscription stays open.
+    pub fn subscribe_contracts(
+        self: &Arc<Self>,
+        contract_ids: &[u64],
+    ) -> impl Stream<Item = Result<FeedMessage, FTXDerivativesError>> {
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        let contract_ids = contract_ids.to_vec();
+        let client = Arc::clone(self);
+
+        tokio::spawn(run_feed(client, contract_ids, tx));
+
+        ReceiverStream::new(rx)
+    }
+}
+
+async fn run_feed(
+    client: Arc<FTXDerivatives>,
+    contract_ids: Vec<u64>,
+    tx: mpsc::Sender<Result<FeedMessage, FTXDerivativesError>>,
+) {
+    let mut backoff = INITIAL_BACKOFF;
+
+    loop {
+        match connect_and_stream(&client, &contract_ids, &tx).await {
+            Ok(()) => return,
+            Err(err) => {
+                if is_unauthorized(&err) {
+                    client.refresh_token().await;
+                }
+
+                if tx.send(Err(err)).await.is_err() {
+                    return;
+                }
+            }
+        }
+
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+fn is_unauthorized(err: &FTXDerivativesError) -> bool {
+    matches!(
+        err,
+        FTXDerivativesError::WebsocketError {
+            source: tokio_tungstenite::tungstenite::Error::Http(response),
+        } if response.status() == StatusCode::UNAUTHORIZED
+    )
+}
+
+async fn connect_and_stream(
+    client: &FTXDerivatives,
+    contract_ids: &[u64],
+    tx: &mpsc::Sender<Result<FeedMessage, FTXDerivativesError>>,
+) -> Result<(), FTXDerivativesError> {
+    let api_key = client.current_token();
+
+    let mut request = WS_URL.into_client_request()?;
+    request.headers_mut().insert(
+        "Authorization",
+        format!("JWT {}", api_key).parse().unwrap(),
+    );
+
+    let (mut socket, _) = connect_async(request).await?;
+
+    let subscribe = serde_json::json!({
+        "type": "subscribe",
+        "contract_ids": contract_ids,
+    });
+    socket.send(Message::Text(subscribe.to_string())).await?;
+
+    while let Some(msg) = socket.next().await {
+        match msg? {
+            Message::Text(text) => {
+                let feed_msg: FeedMessage = serde_json::from_str(&text)?;
+                if tx.send(Ok(feed_msg)).await.is_err() {
+                    return Ok(());
+                }
+            }
+            Message::Ping(payload) => socket.send(Message::Pong(payload)).await?,
+            Message::Close(_) => break,
+            _ => {}
+        }
+    }
+
+    // The server closed the connection cleanly; treat this as a disconnect
+    // so the caller reconnects and re-subscribes.
+    Err(FTXDerivativesError::WebsocketError {
+        source: tokio_tungstenite::tungstenite::Error::ConnectionClosed,
+    })
+}